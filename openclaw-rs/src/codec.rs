@@ -0,0 +1,230 @@
+//! Binary-to-text codec module
+//!
+//! Pluggable encodings for turning binary data into transport-safe text and
+//! back, beyond the crypto module's plain Base64
+
+use base64::{engine::general_purpose, Engine as _};
+use napi::bindgen_prelude::*;
+
+/// Text encodings supported by `encode`/`decode`
+#[napi]
+pub enum TextEncoding {
+    Base64,
+    Base64Url,
+    Base32,
+    Base58,
+    Base65536,
+}
+
+/// Encode `data` as text using the given alphabet
+#[napi]
+pub fn encode(data: Buffer, alphabet: TextEncoding) -> String {
+    match alphabet {
+        TextEncoding::Base64 => general_purpose::STANDARD.encode(&data),
+        TextEncoding::Base64Url => general_purpose::URL_SAFE_NO_PAD.encode(&data),
+        TextEncoding::Base32 => base32::encode(base32::Alphabet::RFC4648 { padding: true }, &data),
+        TextEncoding::Base58 => base58_encode(&data),
+        TextEncoding::Base65536 => base65536_encode(&data),
+    }
+}
+
+/// Decode `input` that was produced by `encode` with the same alphabet
+#[napi]
+pub fn decode(input: String, alphabet: TextEncoding) -> Result<Buffer> {
+    match alphabet {
+        TextEncoding::Base64 => general_purpose::STANDARD
+            .decode(input.as_bytes())
+            .map(Buffer::from)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Base64 decode error: {}", e))),
+        TextEncoding::Base64Url => general_purpose::URL_SAFE_NO_PAD
+            .decode(input.as_bytes())
+            .map(Buffer::from)
+            .map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Base64Url decode error: {}", e),
+                )
+            }),
+        TextEncoding::Base32 => base32::decode(base32::Alphabet::RFC4648 { padding: true }, &input)
+            .map(Buffer::from)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Invalid Base32 input")),
+        TextEncoding::Base58 => base58_decode(&input),
+        TextEncoding::Base65536 => base65536_decode(&input),
+    }
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58 (Bitcoin alphabet) big-integer base conversion, preserving leading
+/// zero bytes as leading '1' characters.
+fn base58_encode(data: &[u8]) -> String {
+    let zero_count = data.iter().take_while(|&&b| b == 0).count();
+
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in data {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zero_count + digits.len());
+    out.extend(std::iter::repeat('1').take(zero_count));
+    out.extend(digits.iter().rev().map(|&d| BASE58_ALPHABET[d as usize] as char));
+    out
+}
+
+fn base58_decode(input: &str) -> Result<Buffer> {
+    let zero_count = input.chars().take_while(|&c| c == '1').count();
+
+    let mut bytes: Vec<u8> = Vec::new();
+    for ch in input.chars() {
+        let digit = BASE58_ALPHABET
+            .iter()
+            .position(|&c| c as char == ch)
+            .ok_or_else(|| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Invalid Base58 character: {}", ch),
+                )
+            })? as u32;
+
+        let mut carry = digit;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xFF) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xFF) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut out = vec![0u8; zero_count];
+    out.extend(bytes.iter().rev());
+    Ok(Buffer::from(out))
+}
+
+// Base65536 packs two bytes per Unicode code point so binary survives
+// code-point-counting transports (e.g. tweets/SMS). Pairs map into a
+// dedicated 65536-entry block, with a second block reserved for a trailing
+// odd byte; both blocks avoid the surrogate range entirely.
+const BASE65536_PAIR_BLOCK: u32 = 0x1_0000;
+const BASE65536_SINGLE_BLOCK: u32 = 0x2_0000;
+
+fn base65536_encode(data: &[u8]) -> String {
+    let mut chunks = data.chunks_exact(2);
+    let mut out = String::with_capacity(data.len().div_ceil(2));
+
+    for pair in &mut chunks {
+        let value = ((pair[0] as u32) << 8) | pair[1] as u32;
+        out.push(char::from_u32(BASE65536_PAIR_BLOCK + value).expect("value maps into plane 1"));
+    }
+
+    if let [last] = *chunks.remainder() {
+        out.push(
+            char::from_u32(BASE65536_SINGLE_BLOCK + last as u32).expect("value fits in a byte"),
+        );
+    }
+
+    out
+}
+
+fn base65536_decode(input: &str) -> Result<Buffer> {
+    let mut out = Vec::with_capacity(input.len() * 2);
+
+    for ch in input.chars() {
+        let code_point = ch as u32;
+
+        if (BASE65536_PAIR_BLOCK..BASE65536_PAIR_BLOCK + 0x1_0000).contains(&code_point) {
+            let value = code_point - BASE65536_PAIR_BLOCK;
+            out.push((value >> 8) as u8);
+            out.push((value & 0xFF) as u8);
+        } else if (BASE65536_SINGLE_BLOCK..BASE65536_SINGLE_BLOCK + 0x100).contains(&code_point) {
+            out.push((code_point - BASE65536_SINGLE_BLOCK) as u8);
+        } else {
+            return Err(Error::new(
+                Status::GenericFailure,
+                format!("Invalid Base65536 code point: U+{:04X}", code_point),
+            ));
+        }
+    }
+
+    Ok(Buffer::from(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_trip() {
+        let data = Buffer::from(b"hello, world!".to_vec());
+        let encoded = encode(data.clone(), TextEncoding::Base64);
+        let decoded = decode(encoded, TextEncoding::Base64).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base64_url_round_trip() {
+        let data = Buffer::from(vec![0xFF, 0xFE, 0x00, 0x01, 0x02]);
+        let encoded = encode(data.clone(), TextEncoding::Base64Url);
+        assert!(!encoded.contains('+') && !encoded.contains('/') && !encoded.contains('='));
+        let decoded = decode(encoded, TextEncoding::Base64Url).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base32_round_trip() {
+        let data = Buffer::from(b"the quick brown fox".to_vec());
+        let encoded = encode(data.clone(), TextEncoding::Base32);
+        let decoded = decode(encoded, TextEncoding::Base32).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base58_round_trip() {
+        let data = Buffer::from(b"hash-like identifier".to_vec());
+        let encoded = encode(data.clone(), TextEncoding::Base58);
+        let decoded = decode(encoded, TextEncoding::Base58).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base58_preserves_leading_zero_bytes() {
+        let data = Buffer::from(vec![0u8, 0u8, 1u8, 2u8, 3u8]);
+        let encoded = encode(data.clone(), TextEncoding::Base58);
+        assert!(encoded.starts_with("11"));
+        let decoded = decode(encoded, TextEncoding::Base58).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base65536_round_trip_even_length() {
+        let data = Buffer::from(vec![0x00, 0x01, 0xAB, 0xCD, 0xFF, 0xFF]);
+        let encoded = encode(data.clone(), TextEncoding::Base65536);
+        let decoded = decode(encoded, TextEncoding::Base65536).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base65536_round_trip_odd_trailing_byte() {
+        let data = Buffer::from(vec![0x00, 0x01, 0xAB, 0x42]);
+        let encoded = encode(data.clone(), TextEncoding::Base65536);
+        let decoded = decode(encoded, TextEncoding::Base65536).unwrap();
+        assert_eq!(decoded.as_ref(), data.as_ref());
+    }
+
+    #[test]
+    fn base65536_rejects_unmapped_code_point() {
+        let result = decode("a".to_string(), TextEncoding::Base65536);
+        assert!(result.is_err());
+    }
+}
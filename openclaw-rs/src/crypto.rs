@@ -74,3 +74,377 @@ pub fn hmac_sha256(key: String, message: String) -> String {
     let tag = hmac::sign(&key, message.as_bytes());
     hex::encode(tag.as_ref())
 }
+
+/// AEAD algorithms available for `encrypt`/`decrypt`
+#[napi]
+pub enum AeadAlgorithm {
+    Aes256Gcm,
+    ChaCha20Poly1305,
+}
+
+const AEAD_NONCE_LEN: usize = 12;
+const AEAD_KEY_LEN: usize = 32;
+
+struct HkdfOutputLen(usize);
+
+impl ring::hkdf::KeyType for HkdfOutputLen {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+fn aead_algorithm(algorithm: AeadAlgorithm) -> &'static ring::aead::Algorithm {
+    match algorithm {
+        AeadAlgorithm::Aes256Gcm => &ring::aead::AES_256_GCM,
+        AeadAlgorithm::ChaCha20Poly1305 => &ring::aead::CHACHA20_POLY1305,
+    }
+}
+
+fn aead_key(key: &[u8], algorithm: AeadAlgorithm) -> Result<ring::aead::LessSafeKey> {
+    if key.len() != AEAD_KEY_LEN {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("AEAD key must be {} bytes, got {}", AEAD_KEY_LEN, key.len()),
+        ));
+    }
+
+    let unbound = ring::aead::UnboundKey::new(aead_algorithm(algorithm), key)
+        .map_err(|_| Error::new(Status::InvalidArg, "Invalid AEAD key material"))?;
+    Ok(ring::aead::LessSafeKey::new(unbound))
+}
+
+/// Derive key material from a passphrase/IKM using HKDF (extract-then-expand).
+///
+/// `salt` and `info` follow the usual HKDF semantics: `salt` domain-separates
+/// the PRK, `info` binds the output to its intended usage. Returns `len` bytes.
+#[napi]
+pub fn derive_key(passphrase: Buffer, salt: Buffer, info: Buffer, len: u32) -> Result<Buffer> {
+    let salt = ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, &salt);
+    let prk = salt.extract(&passphrase);
+
+    let okm = prk
+        .expand(&[&info], HkdfOutputLen(len as usize))
+        .map_err(|_| Error::new(Status::InvalidArg, "HKDF output length is too large"))?;
+
+    let mut out = vec![0u8; len as usize];
+    okm.fill(&mut out)
+        .map_err(|_| Error::new(Status::GenericFailure, "HKDF expand failed"))?;
+
+    Ok(Buffer::from(out))
+}
+
+/// Encrypt `plaintext` with a 256-bit key, returning `nonce || ciphertext || tag`.
+///
+/// A fresh random 96-bit nonce is generated for every call, so the same
+/// (key, nonce) pair is never reused across messages.
+#[napi]
+pub fn encrypt(
+    key: Buffer,
+    plaintext: Buffer,
+    associated_data: Option<Buffer>,
+    algorithm: AeadAlgorithm,
+) -> Result<Buffer> {
+    use ring::rand::SecureRandom;
+
+    let sealing_key = aead_key(&key, algorithm)?;
+
+    let rng = ring::rand::SystemRandom::new();
+    let mut nonce_bytes = [0u8; AEAD_NONCE_LEN];
+    rng.fill(&mut nonce_bytes)
+        .map_err(|_| Error::new(Status::GenericFailure, "Failed to generate nonce"))?;
+    let nonce = ring::aead::Nonce::assume_unique_for_key(nonce_bytes);
+
+    let aad_bytes: &[u8] = associated_data.as_ref().map(|b| b.as_ref()).unwrap_or(&[]);
+    let mut in_out = plaintext.to_vec();
+    sealing_key
+        .seal_in_place_append_tag(nonce, ring::aead::Aad::from(aad_bytes), &mut in_out)
+        .map_err(|_| Error::new(Status::GenericFailure, "Encryption failed"))?;
+
+    let mut out = Vec::with_capacity(AEAD_NONCE_LEN + in_out.len());
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&in_out);
+    Ok(Buffer::from(out))
+}
+
+/// Decrypt a `nonce || ciphertext || tag` blob produced by `encrypt`.
+#[napi]
+pub fn decrypt(
+    key: Buffer,
+    ciphertext: Buffer,
+    associated_data: Option<Buffer>,
+    algorithm: AeadAlgorithm,
+) -> Result<Buffer> {
+    let opening_key = aead_key(&key, algorithm)?;
+
+    if ciphertext.len() < AEAD_NONCE_LEN + aead_algorithm(algorithm).tag_len() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "Ciphertext is shorter than nonce + tag",
+        ));
+    }
+
+    let (nonce_bytes, sealed) = ciphertext.split_at(AEAD_NONCE_LEN);
+    let nonce = ring::aead::Nonce::try_assume_unique_for_key(nonce_bytes)
+        .map_err(|_| Error::new(Status::InvalidArg, "Malformed nonce"))?;
+
+    let aad_bytes: &[u8] = associated_data.as_ref().map(|b| b.as_ref()).unwrap_or(&[]);
+    let mut in_out = sealed.to_vec();
+    let plaintext = opening_key
+        .open_in_place(nonce, ring::aead::Aad::from(aad_bytes), &mut in_out)
+        .map_err(|_| {
+            Error::new(
+                Status::GenericFailure,
+                "Decryption failed: authentication tag mismatch",
+            )
+        })?;
+
+    Ok(Buffer::from(plaintext.to_vec()))
+}
+
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// An Ed25519 keypair. `private` holds the PKCS#8 document, `public` the raw
+/// 32-byte public key.
+#[napi(object)]
+pub struct KeyPair {
+    pub public: Buffer,
+    pub private: Buffer,
+}
+
+/// Generate a new Ed25519 keypair.
+#[napi]
+pub fn generate_keypair() -> Result<KeyPair> {
+    let rng = ring::rand::SystemRandom::new();
+    let pkcs8 = ring::signature::Ed25519KeyPair::generate_pkcs8(&rng)
+        .map_err(|_| Error::new(Status::GenericFailure, "Failed to generate Ed25519 keypair"))?;
+
+    let key_pair = ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref())
+        .map_err(|_| Error::new(Status::GenericFailure, "Failed to parse generated keypair"))?;
+
+    Ok(KeyPair {
+        public: Buffer::from(
+            ring::signature::KeyPair::public_key(&key_pair)
+                .as_ref()
+                .to_vec(),
+        ),
+        private: Buffer::from(pkcs8.as_ref().to_vec()),
+    })
+}
+
+/// Sign `message` with a PKCS#8-encoded Ed25519 private key, producing a
+/// 64-byte detached signature.
+#[napi]
+pub fn sign(private_key: Buffer, message: Buffer) -> Result<Buffer> {
+    let key_pair = ring::signature::Ed25519KeyPair::from_pkcs8(&private_key)
+        .map_err(|_| Error::new(Status::GenericFailure, "Invalid Ed25519 private key"))?;
+
+    let signature = key_pair.sign(&message);
+    Ok(Buffer::from(signature.as_ref().to_vec()))
+}
+
+/// Verify a detached Ed25519 signature. Returns `false` (never throws) when
+/// the signature doesn't match; malformed key/signature lengths are reported
+/// as errors since those indicate caller misuse rather than a forged message.
+#[napi]
+pub fn verify(public_key: Buffer, message: Buffer, signature: Buffer) -> Result<bool> {
+    if public_key.len() != ED25519_PUBLIC_KEY_LEN {
+        return Err(Error::new(
+            Status::GenericFailure,
+            format!(
+                "Ed25519 public key must be {} bytes, got {}",
+                ED25519_PUBLIC_KEY_LEN,
+                public_key.len()
+            ),
+        ));
+    }
+    if signature.len() != ED25519_SIGNATURE_LEN {
+        return Err(Error::new(
+            Status::GenericFailure,
+            format!(
+                "Ed25519 signature must be {} bytes, got {}",
+                ED25519_SIGNATURE_LEN,
+                signature.len()
+            ),
+        ));
+    }
+
+    let public_key = ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, &public_key);
+    Ok(public_key.verify(&message, &signature).is_ok())
+}
+
+/// Verify many (public_key, message, signature) triples in parallel using Rayon.
+///
+/// `public_keys`, `messages`, and `signatures` must have equal length, since
+/// the result is positional against all three; mismatched lengths are a
+/// caller error, not something to silently truncate to the shortest array.
+#[napi]
+pub fn verify_batch(
+    public_keys: Vec<Buffer>,
+    messages: Vec<Buffer>,
+    signatures: Vec<Buffer>,
+) -> Result<Vec<bool>> {
+    use rayon::prelude::*;
+
+    if public_keys.len() != messages.len() || messages.len() != signatures.len() {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+                "public_keys ({}), messages ({}), and signatures ({}) must have equal length",
+                public_keys.len(),
+                messages.len(),
+                signatures.len()
+            ),
+        ));
+    }
+
+    Ok(public_keys
+        .into_par_iter()
+        .zip(messages.into_par_iter())
+        .zip(signatures.into_par_iter())
+        .map(|((public_key, message), signature)| {
+            verify(public_key, message, signature).unwrap_or(false)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_round_trip_aes_256_gcm() {
+        let key = derive_key(
+            Buffer::from(b"correct horse battery staple".to_vec()),
+            Buffer::from(b"salt".to_vec()),
+            Buffer::from(b"test".to_vec()),
+            32,
+        )
+        .unwrap();
+        let plaintext = Buffer::from(b"attack at dawn".to_vec());
+
+        let ciphertext = encrypt(key.clone(), plaintext.clone(), None, AeadAlgorithm::Aes256Gcm)
+            .expect("encrypt should succeed");
+        let decrypted = decrypt(key, ciphertext, None, AeadAlgorithm::Aes256Gcm)
+            .expect("decrypt should succeed");
+
+        assert_eq!(decrypted.as_ref(), plaintext.as_ref());
+    }
+
+    #[test]
+    fn encrypt_decrypt_round_trip_chacha20_poly1305() {
+        let key = Buffer::from(vec![7u8; 32]);
+        let plaintext = Buffer::from(b"the quick brown fox".to_vec());
+        let aad = Some(Buffer::from(b"header".to_vec()));
+
+        let ciphertext = encrypt(
+            key.clone(),
+            plaintext.clone(),
+            aad.clone(),
+            AeadAlgorithm::ChaCha20Poly1305,
+        )
+        .expect("encrypt should succeed");
+        let decrypted = decrypt(key, ciphertext, aad, AeadAlgorithm::ChaCha20Poly1305)
+            .expect("decrypt should succeed");
+
+        assert_eq!(decrypted.as_ref(), plaintext.as_ref());
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_associated_data() {
+        let key = Buffer::from(vec![1u8; 32]);
+        let plaintext = Buffer::from(b"secret".to_vec());
+
+        let ciphertext = encrypt(
+            key.clone(),
+            plaintext,
+            Some(Buffer::from(b"correct-aad".to_vec())),
+            AeadAlgorithm::Aes256Gcm,
+        )
+        .unwrap();
+
+        let result = decrypt(
+            key,
+            ciphertext,
+            Some(Buffer::from(b"wrong-aad".to_vec())),
+            AeadAlgorithm::Aes256Gcm,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decrypt_rejects_short_ciphertext() {
+        let key = Buffer::from(vec![2u8; 32]);
+        let too_short = Buffer::from(vec![0u8; AEAD_NONCE_LEN]);
+
+        let result = decrypt(key, too_short, None, AeadAlgorithm::Aes256Gcm);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn sign_verify_round_trip() {
+        let key_pair = generate_keypair().expect("keypair generation should succeed");
+        let message = Buffer::from(b"group history entry".to_vec());
+
+        let signature = sign(key_pair.private, message.clone()).expect("sign should succeed");
+        let valid = verify(key_pair.public, message, signature).expect("verify should succeed");
+
+        assert!(valid);
+    }
+
+    #[test]
+    fn verify_rejects_tampered_message() {
+        let key_pair = generate_keypair().unwrap();
+        let message = Buffer::from(b"original message".to_vec());
+        let tampered = Buffer::from(b"tampered message".to_vec());
+
+        let signature = sign(key_pair.private, message).unwrap();
+        let valid = verify(key_pair.public, tampered, signature).unwrap();
+
+        assert!(!valid);
+    }
+
+    #[test]
+    fn verify_rejects_malformed_public_key() {
+        let message = Buffer::from(b"message".to_vec());
+        let signature = Buffer::from(vec![0u8; ED25519_SIGNATURE_LEN]);
+        let bad_public_key = Buffer::from(vec![0u8; 4]);
+
+        let result = verify(bad_public_key, message, signature);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_batch_matches_individual_verify() {
+        let key_pair = generate_keypair().unwrap();
+        let message = Buffer::from(b"batched message".to_vec());
+        let signature = sign(key_pair.private, message.clone()).unwrap();
+
+        let results = verify_batch(
+            vec![key_pair.public.clone(), key_pair.public],
+            vec![message.clone(), message],
+            vec![signature.clone(), signature],
+        )
+        .expect("verify_batch should succeed");
+
+        assert_eq!(results, vec![true, true]);
+    }
+
+    #[test]
+    fn verify_batch_rejects_mismatched_lengths() {
+        let key_pair = generate_keypair().unwrap();
+        let message = Buffer::from(b"message".to_vec());
+        let signature = sign(key_pair.private, message.clone()).unwrap();
+
+        let result = verify_batch(
+            vec![key_pair.public],
+            vec![message],
+            vec![signature.clone(), signature],
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -6,6 +6,7 @@
 use napi::bindgen_prelude::*;
 
 pub mod cache;
+pub mod codec;
 pub mod crypto;
 pub mod image;
 pub mod json;
@@ -0,0 +1,209 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+/// A single content-defined chunk produced by FastCDC.
+#[napi(object)]
+pub struct ChunkInfo {
+    pub offset: u64,
+    pub length: u32,
+    pub hash: Buffer,
+}
+
+const fn splitmix64_next(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    (state, z ^ (z >> 31))
+}
+
+const fn generate_gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut state = 0x2545F4914F6CDD1Du64;
+    let mut i = 0;
+    while i < 256 {
+        let (next_state, value) = splitmix64_next(state);
+        table[i] = value;
+        state = next_state;
+        i += 1;
+    }
+    table
+}
+
+/// Precomputed gear table for the rolling fingerprint.
+const GEAR: [u64; 256] = generate_gear_table();
+
+fn mask_with_bits(bits: u32) -> u64 {
+    if bits == 0 {
+        0
+    } else {
+        (1u64 << bits.min(63)) - 1
+    }
+}
+
+/// Derive the FastCDC normalized-chunking masks from the target average size:
+/// `mask_s` (more one-bits, harder to satisfy) is used below `avg_size`,
+/// `mask_l` (fewer one-bits, easier to satisfy) is used above it.
+fn normalized_masks(avg_size: u32) -> (u64, u64) {
+    let bits = (avg_size.max(2) as f64).log2().round() as u32;
+    (mask_with_bits(bits + 1), mask_with_bits(bits.saturating_sub(1)))
+}
+
+/// Scan `data` with the gear rolling hash and return the length of the first
+/// chunk (always `> 0` unless `data` is empty).
+fn find_boundary(data: &[u8], min_size: usize, avg_size: usize, max_size: usize) -> usize {
+    let (mask_s, mask_l) = normalized_masks(avg_size as u32);
+    let hard_max = max_size.min(data.len());
+
+    let mut fp: u64 = 0;
+    let mut pos = 0;
+
+    while pos < hard_max {
+        fp = (fp << 1).wrapping_add(GEAR[data[pos] as usize]);
+        pos += 1;
+
+        if pos < min_size {
+            continue;
+        }
+
+        let mask = if pos < avg_size { mask_s } else { mask_l };
+        if fp & mask == 0 {
+            break;
+        }
+    }
+
+    pos
+}
+
+/// Reject degenerate chunk-size parameters instead of letting `find_boundary`
+/// return a zero-length boundary, which would spin the caller forever (and,
+/// in `Chunker`, grow its output list without bound).
+fn validate_sizes(min_size: usize, avg_size: usize, max_size: usize) -> Result<()> {
+    if min_size == 0 || avg_size == 0 || max_size == 0 {
+        return Err(Error::from_reason(
+            "min_size, avg_size, and max_size must all be greater than zero",
+        ));
+    }
+    if !(min_size <= avg_size && avg_size <= max_size) {
+        return Err(Error::from_reason(
+            "chunk sizes must satisfy min_size <= avg_size <= max_size",
+        ));
+    }
+    Ok(())
+}
+
+fn cut_chunks(data: &[u8], min_size: usize, avg_size: usize, max_size: usize) -> Vec<(usize, &[u8])> {
+    let mut chunks = Vec::new();
+    let mut start = 0usize;
+
+    while start < data.len() {
+        let len = find_boundary(&data[start..], min_size, avg_size, max_size);
+        chunks.push((start, &data[start..start + len]));
+        start += len;
+    }
+
+    chunks
+}
+
+/// Split `data` into content-defined chunks using FastCDC (gear-hash, normalized chunking).
+///
+/// Boundaries are content-dependent, so inserting or deleting bytes only
+/// perturbs the chunks touching the edit instead of reshuffling everything
+/// after it, which is what makes the result usable for dedup-backed sync.
+#[napi]
+pub fn chunk_buffer(
+    data: Buffer,
+    min_size: u32,
+    avg_size: u32,
+    max_size: u32,
+) -> Result<Vec<ChunkInfo>> {
+    let (min_size, avg_size, max_size) = (min_size as usize, avg_size as usize, max_size as usize);
+    validate_sizes(min_size, avg_size, max_size)?;
+
+    let bytes: &[u8] = &data;
+    Ok(cut_chunks(bytes, min_size, avg_size, max_size)
+        .into_iter()
+        .map(|(offset, slice)| ChunkInfo {
+            offset: offset as u64,
+            length: slice.len() as u32,
+            hash: Buffer::from(blake3::hash(slice).as_bytes().to_vec()),
+        })
+        .collect())
+}
+
+/// Streaming FastCDC chunker for multi-gigabyte inputs that shouldn't be
+/// buffered in full. Feed data via `update`, then call `drain` once all
+/// input has been provided to flush the remaining chunks, the last of which
+/// may be short.
+#[napi]
+pub struct Chunker {
+    buffer: Vec<u8>,
+    base_offset: u64,
+    min_size: usize,
+    avg_size: usize,
+    max_size: usize,
+}
+
+#[napi]
+impl Chunker {
+    #[napi(constructor)]
+    pub fn new(min_size: u32, avg_size: u32, max_size: u32) -> Result<Self> {
+        let (min_size, avg_size, max_size) =
+            (min_size as usize, avg_size as usize, max_size as usize);
+        validate_sizes(min_size, avg_size, max_size)?;
+
+        Ok(Self {
+            buffer: Vec::new(),
+            base_offset: 0,
+            min_size,
+            avg_size,
+            max_size,
+        })
+    }
+
+    /// Feed more bytes into the chunker, returning any chunks that became
+    /// final as a result (i.e. there is enough lookahead to know `max_size`
+    /// can't push the boundary further out).
+    #[napi]
+    pub fn update(&mut self, data: Buffer) -> Vec<ChunkInfo> {
+        self.buffer.extend_from_slice(&data);
+        self.take_ready_chunks(false)
+    }
+
+    /// Flush all remaining buffered bytes, cutting them into as many chunks
+    /// as their content boundaries give (the last one may be short).
+    #[napi]
+    pub fn drain(&mut self) -> Vec<ChunkInfo> {
+        self.take_ready_chunks(true)
+    }
+
+    fn take_ready_chunks(&mut self, flush: bool) -> Vec<ChunkInfo> {
+        let mut out = Vec::new();
+
+        loop {
+            if self.buffer.is_empty() {
+                break;
+            }
+            if !flush && self.buffer.len() < self.max_size {
+                break;
+            }
+
+            let len = find_boundary(&self.buffer, self.min_size, self.avg_size, self.max_size);
+            if !flush && len == self.buffer.len() {
+                break;
+            }
+
+            let chunk: Vec<u8> = self.buffer.drain(..len).collect();
+            let offset = self.base_offset;
+            self.base_offset += chunk.len() as u64;
+
+            out.push(ChunkInfo {
+                offset,
+                length: chunk.len() as u32,
+                hash: Buffer::from(blake3::hash(&chunk).as_bytes().to_vec()),
+            });
+        }
+
+        out
+    }
+}
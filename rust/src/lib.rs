@@ -2,10 +2,12 @@ use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
 mod cache;
+mod chunking;
 mod crypto;
 mod json;
 
 pub use cache::*;
+pub use chunking::*;
 pub use crypto::*;
 pub use json::*;
 